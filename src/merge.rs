@@ -107,6 +107,11 @@ impl<'a, T, I: Iterator<Item = T>, F: Fn(&T, &T) -> Ordering, C: Compare<(usize,
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = usize::from(!self.heap.is_empty());
+        (lower, upper_bound(&self.heap, self.iters))
+    }
 }
 
 /// Iterates over the union of many sorted deduplicated iterators and groups equal items with their indices into a [`Vec`].
@@ -219,6 +224,669 @@ impl<'a, T, I: Iterator<Item = T>, F: Fn(&T, &T) -> Ordering, C: Compare<(usize,
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = usize::from(!self.heap.is_empty());
+        (lower, upper_bound(&self.heap, self.iters))
+    }
+}
+
+/// Computes the shared upper size bound of a merge iterator.
+///
+/// Every element that can still be emitted is either sitting in the `heap` or still to come out of one of
+/// the `iters`, and each distinct value (or group) consumes at least one such element, so their saturating
+/// sum bounds the output from above. Returns [`None`] as soon as any input iterator is unbounded.
+fn upper_bound<T, I: Iterator<Item = T>, C: Compare<(usize, T)>>(
+    heap: &BinaryHeap<(usize, T), C>,
+    iters: &[I],
+) -> Option<usize> {
+    let mut upper = Some(heap.len());
+    for iter in iters {
+        upper = match (upper, iter.size_hint().1) {
+            (Some(acc), Some(hint)) => Some(acc.saturating_add(hint)),
+            _ => None,
+        };
+    }
+    upper
+}
+
+/// Iterates over the intersection of many sorted deduplicated iterators.
+///
+/// A value is yielded only when it is present in *every* input iterator.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::intersect_iters;
+///
+/// let it1 = 1u8..=5;
+/// let it2 = 3u8..=7;
+/// let it3 = 2u8..=4;
+/// let mut iters = [it1, it2, it3];
+/// let res: Vec<_> = intersect_iters(&mut iters).collect();
+///
+/// assert_eq!(res, vec![3, 4]);
+/// ```
+pub fn intersect_iters<'a, T: Ord + 'a, I: Iterator<Item = T>>(
+    iters: &'a mut [I],
+) -> impl Iterator<Item = T> + 'a {
+    intersect_iters_by(iters, T::cmp)
+}
+
+/// Iterates over the intersection of many sorted deduplicated iterators, using `cmp` as the comparison operator.
+///
+/// A value is yielded only when it is present in *every* input iterator.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::intersect_iters_by;
+///
+/// let it1 = (1u8..=5).rev();
+/// let it2 = (3u8..=7).rev();
+/// let it3 = (2u8..=4).rev();
+/// let mut iters = [it1, it2, it3];
+/// let res: Vec<_> = intersect_iters_by(&mut iters, |x, y| y.cmp(x)).collect();
+///
+/// assert_eq!(res, vec![4, 3]);
+/// ```
+pub fn intersect_iters_by<'a, T: 'a, I: Iterator<Item = T>, F: Fn(&T, &T) -> Ordering + Copy + 'a>(
+    iters: &'a mut [I],
+    cmp: F,
+) -> impl Iterator<Item = T> + 'a {
+    let n = iters.len();
+    merge_iters_detailed_by(iters, cmp)
+        .filter_map(move |group| (group.len() == n).then(|| project(group)))
+}
+
+/// Iterates over the difference of many sorted deduplicated iterators.
+///
+/// A value is yielded when it is present in the first iterator but in none of the subsequent ones.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::difference_iters;
+///
+/// let it1 = 1u8..=5;
+/// let it2 = 3u8..=7;
+/// let it3 = 2u8..=4;
+/// let mut iters = [it1, it2, it3];
+/// let res: Vec<_> = difference_iters(&mut iters).collect();
+///
+/// assert_eq!(res, vec![1]);
+/// ```
+pub fn difference_iters<'a, T: Ord + 'a, I: Iterator<Item = T>>(
+    iters: &'a mut [I],
+) -> impl Iterator<Item = T> + 'a {
+    difference_iters_by(iters, T::cmp)
+}
+
+/// Iterates over the difference of many sorted deduplicated iterators, using `cmp` as the comparison operator.
+///
+/// A value is yielded when it is present in the first iterator but in none of the subsequent ones.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::difference_iters_by;
+///
+/// let it1 = (1u8..=5).rev();
+/// let it2 = (3u8..=7).rev();
+/// let it3 = (2u8..=4).rev();
+/// let mut iters = [it1, it2, it3];
+/// let res: Vec<_> = difference_iters_by(&mut iters, |x, y| y.cmp(x)).collect();
+///
+/// assert_eq!(res, vec![1]);
+/// ```
+pub fn difference_iters_by<'a, T: 'a, I: Iterator<Item = T>, F: Fn(&T, &T) -> Ordering + Copy + 'a>(
+    iters: &'a mut [I],
+    cmp: F,
+) -> impl Iterator<Item = T> + 'a {
+    merge_iters_detailed_by(iters, cmp)
+        .filter_map(|group| (group.len() == 1 && group[0].0 == 0).then(|| project(group)))
+}
+
+/// Iterates over the symmetric difference of many sorted deduplicated iterators.
+///
+/// A value is yielded when it is present in an odd number of the input iterators.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::symmetric_difference_iters;
+///
+/// let it1 = 1u8..=5;
+/// let it2 = 3u8..=7;
+/// let it3 = 2u8..=4;
+/// let mut iters = [it1, it2, it3];
+/// let res: Vec<_> = symmetric_difference_iters(&mut iters).collect();
+///
+/// assert_eq!(res, vec![1, 3, 4, 6, 7]);
+/// ```
+pub fn symmetric_difference_iters<'a, T: Ord + 'a, I: Iterator<Item = T>>(
+    iters: &'a mut [I],
+) -> impl Iterator<Item = T> + 'a {
+    symmetric_difference_iters_by(iters, T::cmp)
+}
+
+/// Iterates over the symmetric difference of many sorted deduplicated iterators, using `cmp` as the comparison operator.
+///
+/// A value is yielded when it is present in an odd number of the input iterators.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::symmetric_difference_iters_by;
+///
+/// let it1 = (1u8..=5).rev();
+/// let it2 = (3u8..=7).rev();
+/// let it3 = (2u8..=4).rev();
+/// let mut iters = [it1, it2, it3];
+/// let res: Vec<_> = symmetric_difference_iters_by(&mut iters, |x, y| y.cmp(x)).collect();
+///
+/// assert_eq!(res, vec![7, 6, 4, 3, 1]);
+/// ```
+pub fn symmetric_difference_iters_by<
+    'a,
+    T: 'a,
+    I: Iterator<Item = T>,
+    F: Fn(&T, &T) -> Ordering + Copy + 'a,
+>(
+    iters: &'a mut [I],
+    cmp: F,
+) -> impl Iterator<Item = T> + 'a {
+    merge_iters_detailed_by(iters, cmp)
+        .filter_map(|group| (group.len() % 2 == 1).then(|| project(group)))
+}
+
+/// Iterates over the values appearing in at least `k` of many sorted deduplicated iterators.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::merge_iters_threshold;
+///
+/// let it1 = 1u8..=5;
+/// let it2 = 3u8..=7;
+/// let it3 = 2u8..=4;
+/// let mut iters = [it1, it2, it3];
+/// let res: Vec<_> = merge_iters_threshold(&mut iters, 2).collect();
+///
+/// assert_eq!(res, vec![2, 3, 4, 5]);
+/// ```
+pub fn merge_iters_threshold<'a, T: Ord + 'a, I: Iterator<Item = T>>(
+    iters: &'a mut [I],
+    k: usize,
+) -> impl Iterator<Item = T> + 'a {
+    merge_iters_threshold_by(iters, k, T::cmp)
+}
+
+/// Iterates over the values appearing in at least `k` of many sorted deduplicated iterators, using `cmp` as the comparison operator.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::merge_iters_threshold_by;
+///
+/// let it1 = (1u8..=5).rev();
+/// let it2 = (3u8..=7).rev();
+/// let it3 = (2u8..=4).rev();
+/// let mut iters = [it1, it2, it3];
+/// let res: Vec<_> = merge_iters_threshold_by(&mut iters, 2, |x, y| y.cmp(x)).collect();
+///
+/// assert_eq!(res, vec![5, 4, 3, 2]);
+/// ```
+pub fn merge_iters_threshold_by<
+    'a,
+    T: 'a,
+    I: Iterator<Item = T>,
+    F: Fn(&T, &T) -> Ordering + Copy + 'a,
+>(
+    iters: &'a mut [I],
+    k: usize,
+    cmp: F,
+) -> impl Iterator<Item = T> + 'a {
+    merge_iters_detailed_by(iters, cmp)
+        .filter_map(move |group| (group.len() >= k).then(|| project(group)))
+}
+
+/// Projects an equal-group produced by [`DetailedMergeIterator`] back to its value.
+///
+/// Every entry of the group shares the same value, so any one of them will do.
+fn project<T>(group: Vec<(usize, T)>) -> T {
+    group
+        .into_iter()
+        .next()
+        .expect("a group is never empty")
+        .1
+}
+
+/// Merges many sorted deduplicated iterators, folding each group of equal items through a closure.
+///
+/// Instead of dropping duplicates, every group of equal items is reduced through `fold` starting from a
+/// fresh copy of `init`, yielding one accumulator per distinct key.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::merge_iters_coalesce;
+///
+/// let it1 = 1u8..=3;
+/// let it2 = 2u8..=4;
+/// let mut iters = [it1, it2];
+/// // Count how many inputs each distinct value appears in.
+/// let res: Vec<_> = merge_iters_coalesce(&mut iters, 0u32, |acc, _| acc + 1).collect();
+///
+/// assert_eq!(res, vec![1, 2, 2, 1]);
+/// ```
+pub fn merge_iters_coalesce<'a, T: Ord + 'a, I: Iterator<Item = T>, A: Clone, G: Fn(A, T) -> A>(
+    iters: &mut [I],
+    init: A,
+    fold: G,
+) -> CoalesceIterator<
+    '_,
+    T,
+    I,
+    impl Fn(&T, &T) -> Ordering + 'a,
+    impl Fn(&(usize, T), &(usize, T)) -> Ordering + 'a,
+    A,
+    G,
+> {
+    merge_iters_coalesce_by(iters, init, fold, T::cmp)
+}
+
+/// Merges many sorted deduplicated iterators, folding each group of equal items through a closure, using `cmp` as the comparison operator.
+///
+/// The key ordering `cmp` is kept separate from `fold`, so callers can merge on a key while combining the
+/// associated payloads — for example summing the counts of equal `(key, count)` entries.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::merge_iters_coalesce_by;
+///
+/// let it1 = vec![(1u8, 10u32), (2, 20)].into_iter();
+/// let it2 = vec![(1u8, 5u32), (3, 7)].into_iter();
+/// let mut iters = [it1, it2];
+/// let res: Vec<_> = merge_iters_coalesce_by(
+///     &mut iters,
+///     (0u8, 0u32),
+///     |acc: (u8, u32), x: (u8, u32)| (x.0, acc.1 + x.1),
+///     |x: &(u8, u32), y: &(u8, u32)| x.0.cmp(&y.0),
+/// )
+/// .collect();
+///
+/// assert_eq!(res, vec![(1, 15), (2, 20), (3, 7)]);
+/// ```
+pub fn merge_iters_coalesce_by<
+    'a,
+    T,
+    I: Iterator<Item = T>,
+    A: Clone,
+    G: Fn(A, T) -> A,
+    F: Fn(&T, &T) -> Ordering + Copy + 'a,
+>(
+    iters: &mut [I],
+    init: A,
+    fold: G,
+    cmp: F,
+) -> CoalesceIterator<'_, T, I, F, impl Fn(&(usize, T), &(usize, T)) -> Ordering + 'a, A, G> {
+    let mut vec = Vec::with_capacity(iters.len());
+    for (i, iter) in iters.iter_mut().enumerate() {
+        if let Some(x) = iter.next() {
+            vec.push((i, x));
+        }
+    }
+    let heap = BinaryHeap::from_vec_cmp(vec, move |(_, x): &(usize, T), (_, y): &(usize, T)| {
+        cmp(y, x)
+    });
+    CoalesceIterator {
+        iters,
+        cmp,
+        heap,
+        init,
+        fold,
+    }
+}
+
+pub struct CoalesceIterator<
+    'a,
+    T,
+    I: Iterator<Item = T>,
+    F: Fn(&T, &T) -> Ordering,
+    C: Compare<(usize, T)>,
+    A: Clone,
+    G: Fn(A, T) -> A,
+> {
+    iters: &'a mut [I],
+    cmp: F,
+    heap: BinaryHeap<(usize, T), C>,
+    init: A,
+    fold: G,
+}
+
+impl<
+        'a,
+        T,
+        I: Iterator<Item = T>,
+        F: Fn(&T, &T) -> Ordering,
+        C: Compare<(usize, T)>,
+        A: Clone,
+        G: Fn(A, T) -> A,
+    > Iterator for CoalesceIterator<'a, T, I, F, C, A, G>
+{
+    type Item = A;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.heap.is_empty() {
+            let key = {
+                let mut peek = self.heap.peek_mut().unwrap();
+                let entry = peek.deref_mut();
+                if let Some(mut x) = self.iters[entry.0].next() {
+                    swap(&mut entry.1, &mut x);
+                    x
+                } else {
+                    PeekMut::pop(peek).1
+                }
+            };
+            let mut acc = self.init.clone();
+            while let Some(mut peek) = self.heap.peek_mut() {
+                if (self.cmp)(&key, &peek.1) == Ordering::Equal {
+                    let entry = peek.deref_mut();
+                    if let Some(mut x) = self.iters[entry.0].next() {
+                        swap(&mut entry.1, &mut x);
+                        acc = (self.fold)(acc, x);
+                    } else {
+                        acc = (self.fold)(acc, PeekMut::pop(peek).1);
+                    }
+                } else {
+                    break;
+                }
+            }
+            Some((self.fold)(acc, key))
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterates over the union of many sorted deduplicated iterators, and can be rewound for another pass.
+///
+/// Each input iterator is cloned up front, so [`rewind`](RewindableMergeIterator::rewind) can restore the
+/// original heads and replay the merge — handy for running a counting pass followed by an emitting pass
+/// over the same inputs without rebuilding the whole call.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::merge_iters_rewind;
+///
+/// let it1 = 1u8..=3;
+/// let it2 = 2u8..=4;
+/// let mut iters = [it1, it2];
+/// let mut merge = merge_iters_rewind(&mut iters);
+/// let first: Vec<_> = merge.by_ref().collect();
+/// merge.rewind();
+/// let second: Vec<_> = merge.collect();
+///
+/// assert_eq!(first, vec![1, 2, 3, 4]);
+/// assert_eq!(second, vec![1, 2, 3, 4]);
+/// ```
+pub fn merge_iters_rewind<'a, T: Ord + 'a, I: Iterator<Item = T> + Clone>(
+    iters: &mut [I],
+) -> RewindableMergeIterator<
+    '_,
+    T,
+    I,
+    impl Fn(&T, &T) -> Ordering + 'a,
+    impl Fn(&(usize, T), &(usize, T)) -> Ordering + 'a,
+> {
+    merge_iters_rewind_by(iters, T::cmp)
+}
+
+/// Iterates over the union of many sorted deduplicated iterators, and can be rewound for another pass, using `cmp` as the comparison operator.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::merge_iters_rewind_by;
+///
+/// let it1 = (1u8..=3).rev();
+/// let it2 = (2u8..=4).rev();
+/// let mut iters = [it1, it2];
+/// let mut merge = merge_iters_rewind_by(&mut iters, |x, y| y.cmp(x));
+/// let first: Vec<_> = merge.by_ref().collect();
+/// merge.rewind();
+/// let second: Vec<_> = merge.collect();
+///
+/// assert_eq!(first, vec![4, 3, 2, 1]);
+/// assert_eq!(second, vec![4, 3, 2, 1]);
+/// ```
+pub fn merge_iters_rewind_by<
+    'a,
+    T,
+    I: Iterator<Item = T> + Clone,
+    F: Fn(&T, &T) -> Ordering + Copy + 'a,
+>(
+    iters: &mut [I],
+    cmp: F,
+) -> RewindableMergeIterator<'_, T, I, F, impl Fn(&(usize, T), &(usize, T)) -> Ordering + 'a> {
+    let origins = iters.to_vec();
+    let mut vec = Vec::with_capacity(iters.len());
+    for (i, iter) in iters.iter_mut().enumerate() {
+        if let Some(x) = iter.next() {
+            vec.push((i, x));
+        }
+    }
+    let heap = BinaryHeap::from_vec_cmp(vec, move |(_, x): &(usize, T), (_, y): &(usize, T)| {
+        cmp(y, x)
+    });
+    RewindableMergeIterator {
+        iters,
+        cmp,
+        heap,
+        origins,
+    }
+}
+
+pub struct RewindableMergeIterator<
+    'a,
+    T,
+    I: Iterator<Item = T> + Clone,
+    F: Fn(&T, &T) -> Ordering,
+    C: Compare<(usize, T)>,
+> {
+    iters: &'a mut [I],
+    cmp: F,
+    heap: BinaryHeap<(usize, T), C>,
+    origins: Vec<I>,
+}
+
+impl<'a, T, I: Iterator<Item = T> + Clone, F: Fn(&T, &T) -> Ordering, C: Compare<(usize, T)>>
+    RewindableMergeIterator<'a, T, I, F, C>
+{
+    /// Restores every input iterator to its initial state and rebuilds the heap, so the merge can be replayed.
+    pub fn rewind(&mut self) {
+        self.iters.clone_from_slice(&self.origins);
+        self.heap.clear();
+        for (i, iter) in self.iters.iter_mut().enumerate() {
+            if let Some(x) = iter.next() {
+                self.heap.push((i, x));
+            }
+        }
+    }
+}
+
+impl<'a, T, I: Iterator<Item = T> + Clone, F: Fn(&T, &T) -> Ordering, C: Compare<(usize, T)>>
+    Iterator for RewindableMergeIterator<'a, T, I, F, C>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.heap.is_empty() {
+            let res = {
+                let mut peek = self.heap.peek_mut().unwrap();
+                let entry = peek.deref_mut();
+                if let Some(mut x) = self.iters[entry.0].next() {
+                    swap(&mut entry.1, &mut x);
+                    x
+                } else {
+                    PeekMut::pop(peek).1
+                }
+            };
+            while let Some(mut peek) = self.heap.peek_mut() {
+                if (self.cmp)(&res, &peek.1) == Ordering::Equal {
+                    let entry = peek.deref_mut();
+                    if let Some(mut x) = self.iters[entry.0].next() {
+                        swap(&mut entry.1, &mut x);
+                    } else {
+                        PeekMut::pop(peek);
+                    }
+                } else {
+                    break;
+                }
+            }
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator that can skip ahead to a target value.
+///
+/// [`seek`](SeekableIterator::seek) advances the iterator to and returns the first element greater
+/// than or equal to `target`, or [`None`] once the iterator is exhausted. The default implementation
+/// simply loops on [`next`](Iterator::next); iterators backed by a contiguous slice can override it
+/// with an exponential-then-binary search, which is what makes the galloping intersection below cheap
+/// when one input is much smaller than another.
+pub trait SeekableIterator: Iterator
+where
+    Self::Item: Ord,
+{
+    /// Advances to and returns the first element greater than or equal to `target`.
+    fn seek(&mut self, target: &Self::Item) -> Option<Self::Item> {
+        while let Some(x) = self.next() {
+            if x.cmp(target).is_ge() {
+                return Some(x);
+            }
+        }
+        None
+    }
+}
+
+impl<T: Ord> SeekableIterator for core::slice::Iter<'_, T> {
+    fn seek(&mut self, target: &Self::Item) -> Option<Self::Item> {
+        let target: &T = *target;
+        let slice = self.as_slice();
+        // Exponential search for a window bracketing the first element `>= target`.
+        let mut bound = 1;
+        while bound < slice.len() && slice[bound] < *target {
+            bound *= 2;
+        }
+        let lo = bound / 2;
+        let hi = bound.min(slice.len());
+        let idx = lo + slice[lo..hi].partition_point(|x| x < target);
+        self.nth(idx)
+    }
+}
+
+impl<T: Ord> SeekableIterator for core::ops::Range<T> where core::ops::Range<T>: Iterator<Item = T> {}
+
+/// Intersects many sorted deduplicated iterators using a galloping seek.
+///
+/// Unlike [`intersect_iters`], this keeps a single representative element per iterator and repeatedly
+/// [`seek`](SeekableIterator::seek)s every head up to the current maximum. With iterators whose `seek` is
+/// logarithmic, such as slice iterators, the cost scales with the smallest input (times a logarithmic seek
+/// factor) rather than the sum of all input lengths, which suits posting-list style intersections where the
+/// inputs differ wildly in size. With the default `seek` (used for [`Range`](core::ops::Range)), every
+/// skipped element is still stepped through, so the cost stays proportional to the sum of the input lengths.
+///
+/// # Examples
+///
+/// ```
+/// use iter_set_ops::intersect_iters_seek;
+///
+/// let a = [1u8, 2, 3, 4, 5];
+/// let b = [3u8, 4, 5, 6, 7];
+/// let c = [2u8, 3, 4];
+/// let mut iters = [a.iter(), b.iter(), c.iter()];
+/// let res: Vec<_> = intersect_iters_seek(&mut iters).copied().collect();
+///
+/// assert_eq!(res, vec![3, 4]);
+/// ```
+pub fn intersect_iters_seek<I>(iters: &mut [I]) -> SeekIntersection<'_, I>
+where
+    I: SeekableIterator,
+    I::Item: Ord + Clone,
+{
+    let mut heads = Vec::with_capacity(iters.len());
+    for iter in iters.iter_mut() {
+        if let Some(x) = iter.next() {
+            heads.push(x);
+        }
+    }
+    let live = heads.len() == iters.len() && !iters.is_empty();
+    SeekIntersection { iters, heads, live }
+}
+
+pub struct SeekIntersection<'a, I: Iterator>
+where
+    I::Item: Ord,
+{
+    iters: &'a mut [I],
+    heads: Vec<I::Item>,
+    live: bool,
+}
+
+impl<I> Iterator for SeekIntersection<'_, I>
+where
+    I: SeekableIterator,
+    I::Item: Ord + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.live {
+            return None;
+        }
+        loop {
+            let mut target = self.heads[0].clone();
+            for head in &self.heads[1..] {
+                if *head > target {
+                    target = head.clone();
+                }
+            }
+            let mut all_equal = true;
+            for (i, iter) in self.iters.iter_mut().enumerate() {
+                if self.heads[i] < target {
+                    match iter.seek(&target) {
+                        Some(x) => self.heads[i] = x,
+                        None => {
+                            self.live = false;
+                            return None;
+                        }
+                    }
+                }
+                if self.heads[i] != target {
+                    all_equal = false;
+                }
+            }
+            if all_equal {
+                for (i, iter) in self.iters.iter_mut().enumerate() {
+                    match iter.next() {
+                        Some(x) => self.heads[i] = x,
+                        None => {
+                            self.live = false;
+                            break;
+                        }
+                    }
+                }
+                return Some(target);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -270,4 +938,160 @@ mod tests {
         assert_eq!(res, vec![vec![(1, 3)], vec![(0, 2), (1, 2)], vec![(0, 1)]]);
         assert!(iters[1].next().is_none());
     }
+
+    #[test]
+    fn test_intersect() {
+        let it1 = 1u8..=5;
+        let it2 = 3u8..=7;
+        let it3 = 2u8..=4;
+        let mut iters = [it1, it2, it3];
+        let res: Vec<_> = intersect_iters(&mut iters).collect();
+
+        assert_eq!(res, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_difference() {
+        let it1 = 1u8..=5;
+        let it2 = 3u8..=7;
+        let it3 = 2u8..=4;
+        let mut iters = [it1, it2, it3];
+        let res: Vec<_> = difference_iters(&mut iters).collect();
+
+        assert_eq!(res, vec![1]);
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let it1 = 1u8..=5;
+        let it2 = 3u8..=7;
+        let it3 = 2u8..=4;
+        let mut iters = [it1, it2, it3];
+        let res: Vec<_> = symmetric_difference_iters(&mut iters).collect();
+
+        assert_eq!(res, vec![1, 3, 4, 6, 7]);
+    }
+
+    #[test]
+    fn test_merge_threshold() {
+        let it1 = 1u8..=5;
+        let it2 = 3u8..=7;
+        let it3 = 2u8..=4;
+        let mut iters = [it1, it2, it3];
+        let res: Vec<_> = merge_iters_threshold(&mut iters, 2).collect();
+
+        assert_eq!(res, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_seek_default() {
+        let mut it = 0u8..10;
+        assert_eq!(it.seek(&4), Some(4));
+        assert_eq!(it.next(), Some(5));
+        assert_eq!(it.seek(&20), None);
+    }
+
+    #[test]
+    fn test_seek_slice() {
+        let v = [1u8, 3, 5, 7, 9];
+        let mut it = v.iter();
+        assert_eq!(it.seek(&&4), Some(&5));
+        assert_eq!(it.next(), Some(&7));
+        assert_eq!(it.seek(&&100), None);
+    }
+
+    #[test]
+    fn test_intersect_seek() {
+        let a = [1u8, 2, 3, 4, 5];
+        let b = [3u8, 4, 5, 6, 7];
+        let c = [2u8, 3, 4];
+        let mut iters = [a.iter(), b.iter(), c.iter()];
+        let res: Vec<_> = intersect_iters_seek(&mut iters).copied().collect();
+
+        assert_eq!(res, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_intersect_seek_disjoint() {
+        let a = [1u8, 2, 3];
+        let b = [4u8, 5, 6];
+        let mut iters = [a.iter(), b.iter()];
+        let res: Vec<_> = intersect_iters_seek(&mut iters).copied().collect();
+
+        assert!(res.is_empty());
+    }
+
+    #[test]
+    fn test_merge_coalesce() {
+        let it1 = 1u8..=3;
+        let it2 = 2u8..=4;
+        let mut iters = [it1, it2];
+        let res: Vec<_> = merge_iters_coalesce(&mut iters, 0u32, |acc, _| acc + 1).collect();
+
+        assert_eq!(res, vec![1, 2, 2, 1]);
+    }
+
+    #[test]
+    fn test_merge_coalesce_by() {
+        let it1 = vec![(1u8, 10u32), (2, 20)].into_iter();
+        let it2 = vec![(1u8, 5u32), (3, 7)].into_iter();
+        let mut iters = [it1, it2];
+        let res: Vec<_> = merge_iters_coalesce_by(
+            &mut iters,
+            (0u8, 0u32),
+            |acc: (u8, u32), x: (u8, u32)| (x.0, acc.1 + x.1),
+            |x: &(u8, u32), y: &(u8, u32)| x.0.cmp(&y.0),
+        )
+        .collect();
+
+        assert_eq!(res, vec![(1, 15), (2, 20), (3, 7)]);
+    }
+
+    #[test]
+    fn test_merge_rewind() {
+        let it1 = 1u8..=3;
+        let it2 = 2u8..=4;
+        let mut iters = [it1, it2];
+        let mut merge = merge_iters_rewind(&mut iters);
+        let first: Vec<_> = merge.by_ref().collect();
+        merge.rewind();
+        let second: Vec<_> = merge.collect();
+
+        assert_eq!(first, vec![1, 2, 3, 4]);
+        assert_eq!(second, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let it1 = 1u8..=5;
+        let it2 = 3u8..=7;
+        let it3 = 2u8..=4;
+        let mut iters = [it1, it2, it3];
+        let merge = merge_iters(&mut iters);
+        let (lower, upper) = merge.size_hint();
+        let len = merge.count();
+
+        assert!(lower <= len);
+        assert!(match upper {
+            Some(upper) => len <= upper,
+            None => true,
+        });
+    }
+
+    #[test]
+    fn test_size_hint_detailed() {
+        let it1 = 1u8..=5;
+        let it2 = 3u8..=7;
+        let it3 = 2u8..=4;
+        let mut iters = [it1, it2, it3];
+        let merge = merge_iters_detailed(&mut iters);
+        let (lower, upper) = merge.size_hint();
+        let len = merge.count();
+
+        assert!(lower <= len);
+        assert!(match upper {
+            Some(upper) => len <= upper,
+            None => true,
+        });
+    }
 }